@@ -1,5 +1,6 @@
 use std::collections::hash_map::HashMap;
-use std::ops::{Index, Range, RangeFrom, RangeFull, RangeTo};
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut, Range, RangeFrom, RangeFull, RangeTo};
 
 /// Vector where each element has an associated name.
 ///
@@ -10,18 +11,27 @@ use std::ops::{Index, Range, RangeFrom, RangeFull, RangeTo};
 ///
 /// Internally, a `NamedVec<T>` is a wrapper around a `Vec<T>`, with names
 /// and their corresponding indices stored as a `HashMap<String, usize>`.
+///
+/// The second type parameter `I` is the integer index type used for positional
+/// access. It defaults to `usize`, but a distinct newtype can be supplied (see
+/// [`define_index_type!`](macro.define_index_type.html)) so that indices from
+/// one `NamedVec` cannot be accidentally used with another. All internal
+/// bookkeeping is still done with `usize`; `I` is purely the public currency
+/// for positional lookups.
 #[derive(Debug, PartialEq)]
-pub struct NamedVec<T: Named> {
+pub struct NamedVec<T: Named, I: Idx = usize> {
     map: HashMap<String, usize>,
     items: Vec<T>,
+    _index: PhantomData<I>,
 }
 
-impl<T: Named> NamedVec<T> {
+impl<T: Named, I: Idx> NamedVec<T, I> {
     /// Creates an empty `NamedVec<T>`.
     pub fn new() -> Self {
         NamedVec {
             map: HashMap::new(),
             items: Vec::new(),
+            _index: PhantomData,
         }
     }
 
@@ -33,6 +43,7 @@ impl<T: Named> NamedVec<T> {
         NamedVec {
             map: HashMap::with_capacity(capacity),
             items: Vec::with_capacity(capacity),
+            _index: PhantomData,
         }
     }
 
@@ -50,6 +61,47 @@ impl<T: Named> NamedVec<T> {
         }
     }
 
+    /// Inserts an element at position `lookup`, shifting all elements after it
+    /// to the right.
+    ///
+    /// The position can be given as the collection's index type `I` or a `&str`,
+    /// just like [`get()`](#method.get). To preserve the name-uniqueness
+    /// invariant that [`push()`](#method.push) maintains, if an element with the
+    /// same name already exists it is removed first. The new element is then
+    /// placed in front of the element that occupied the requested position (or
+    /// at the end, if the position was the length), so when the removed element
+    /// sat before that position the new element ends up one index lower.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resolved position is greater than the vector's length, or
+    /// if a `&str` lookup refers to a nonexistent element.
+    pub fn insert<A>(&mut self, lookup: A, item: T) where A: NamedIndex<T, I> {
+        // Resolve the target position up front, while the map still contains the
+        // item's own name. This keeps `insert(name, item_with_that_name)` from
+        // panicking once the old occurrence is removed below.
+        let mut i = lookup.resolve(self)
+            .expect("no element found for the given insert position");
+
+        // Drop any existing element with this name first, mirroring `push`. If
+        // it sat before the target, the target shifts left by one to compensate.
+        if let Some(old) = self.map.get(item.name()).cloned() {
+            self.remove_at(old);
+            if old < i {
+                i -= 1;
+            }
+        }
+
+        // Everything from `i` onwards shifts right by one.
+        for index in self.map.values_mut() {
+            if *index >= i {
+                *index += 1;
+            }
+        }
+        self.map.insert(item.name().to_owned(), i);
+        self.items.insert(i, item);
+    }
+
     /// Returns the number of elements the vector can hold without reallocating.
     pub fn capacity(&self) -> usize {
         self.items.capacity()
@@ -87,6 +139,20 @@ impl<T: Named> NamedVec<T> {
         }
     }
 
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, removes all elements `e` for which `f(&e)` returns
+    /// `false`. This operation preserves the order of the retained elements.
+    pub fn retain<F>(&mut self, f: F) where F: FnMut(&T) -> bool {
+        // Retaining compacts `items` and invalidates most stored indices, so
+        // clear the map and rebuild it in one pass over the survivors.
+        self.items.retain(f);
+        self.map.clear();
+        for (i, item) in self.items.iter().enumerate() {
+            self.map.insert(item.name().to_owned(), i);
+        }
+    }
+
     /// Clears the vector, removing all values.
     pub fn clear(&mut self) {
         self.map.clear();
@@ -98,47 +164,90 @@ impl<T: Named> NamedVec<T> {
         self.len() == 0
     }
 
-    /// Returns a reference to an element.
+    /// Returns a reference to an element or subslice depending on the type of
+    /// index.
     ///
-    /// This function's argument can be a `usize`, e.g. `named_vec.get(0)`,
-    /// or a `&str`, e.g. `named_vec.get("foo")`.
-    /// These will access elements by position or name, respectively.
+    /// The argument can be the collection's index type `I` (`usize` by
+    /// default), e.g. `named_vec.get(0)`, or a `&str`, e.g.
+    /// `named_vec.get("foo")`, accessing a single element by position or
+    /// name respectively. It can also be a `usize` range, e.g.
+    /// `named_vec.get(1..3)`, which yields a subslice.
     ///
-    /// Returns `None` if a `usize` argument is out of bounds or if
-    /// a `&str` argument refers to a nonexistent element.
-    pub fn get<'a, A: 'a>(&self, lookup: A) -> Option<&T> where A: Into<Lookup<'a>> {
-        self.index_from_lookup(lookup.into()).and_then(|i| self.items.get(i))
+    /// Returns `None` if an index argument is out of bounds, if a `&str`
+    /// argument refers to a nonexistent element, or if a range is out of
+    /// bounds.
+    pub fn get<S: NamedSliceIndex<T, I>>(&self, index: S) -> Option<&S::Output> {
+        index.get(self)
     }
 
-    /// Returns a mutable reference to an element.
+    /// Returns a mutable reference to an element or subslice depending on the
+    /// type of index.
     ///
     /// See [`get()`](#method.get) for more information.
-    pub fn get_mut <'a, A: 'a>(&mut self, lookup: A) -> Option<&mut T>
-    where A: Into<Lookup<'a>> {
-        self.index_from_lookup(lookup.into()).and_then(move |i| self.items.get_mut(i))
+    pub fn get_mut<S: NamedSliceIndex<T, I>>(&mut self, index: S) -> Option<&mut S::Output> {
+        index.get_mut(self)
+    }
+
+    /// Positional access by a raw `usize`, used by the `NamedSliceIndex`
+    /// implementation that [`define_index_type!`](macro.define_index_type.html)
+    /// generates. Not part of the public API.
+    #[doc(hidden)]
+    pub fn get_at(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    /// Mutable counterpart to [`get_at()`](#method.get_at). Not part of the
+    /// public API.
+    #[doc(hidden)]
+    pub fn get_at_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.items.get_mut(index)
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Returns an iterator that allows modifying each element.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.items.iter_mut()
+    }
+
+    /// Returns an iterator that pairs each element with its index and name.
+    ///
+    /// The index is handed back as the collection's index type `I`, and the
+    /// name is read through the [`Named`](trait.Named.html) trait, so the tuples
+    /// are yielded in the same order as the elements themselves.
+    pub fn named_iter(&self) -> impl Iterator<Item = (I, &str, &T)> {
+        self.items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (I::from_usize(i), item.name(), item))
     }
 
     /// Swaps two elements.
     ///
-    /// Each element can be either a `usize` or a `&str`.
-    /// See [`get()`](#method.get) for more information on arguments.
+    /// Each element can be referred to by the collection's index type `I` or by
+    /// a `&str`. See [`get()`](#method.get) for more information on arguments.
     ///
     /// # Panics
     ///
-    /// * Panics if a `usize` argument is out of bounds.
+    /// * Panics if an index argument is out of bounds.
     /// * Panics if a `&str` argument is an invalid name.
-    pub fn swap<'a, 'b, A: 'a, B: 'b>(&mut self, first: A, second: B)
-    where A: Into<Lookup<'a>> + Copy, B: Into<Lookup<'b>> + Copy {
-        let old_i1 = self.index_from_lookup(first.into()).unwrap();
-        let old_i2 = self.index_from_lookup(second.into()).unwrap();
+    pub fn swap<A, B>(&mut self, first: A, second: B)
+    where A: NamedIndex<T, I>, B: NamedIndex<T, I> {
+        let old_i1 = first.resolve(self).unwrap();
+        let old_i2 = second.resolve(self).unwrap();
+        assert!(old_i1 < self.items.len() && old_i2 < self.items.len(),
+                "swap index out of bounds");
 
         // Don't bother swapping (and allocating Strings!) if the two items are the same
         if old_i1 == old_i2 {
             return;
         }
 
-        let old_s1 = self.name_from_lookup(first.into()).unwrap();
-        let old_s2 = self.name_from_lookup(second.into()).unwrap();
+        let old_s1 = self.items[old_i1].name().to_owned();
+        let old_s2 = self.items[old_i2].name().to_owned();
 
         self.map.insert(old_s1, old_i2);
         self.map.insert(old_s2, old_i1);
@@ -161,161 +270,348 @@ impl<T: Named> NamedVec<T> {
         }
     }
 
-    fn index_from_lookup(&self, lookup: Lookup) -> Option<usize> {
-        match lookup {
-            Lookup::Name(name) => {
-                self.map.get(name).cloned()
-            },
-            Lookup::Index(index) => {
-                Some(index)
-            },
+    /// Removes and returns the element resolved from `lookup`, shifting all
+    /// elements after it to the left.
+    ///
+    /// This function's argument can be the collection's index type `I` or a
+    /// `&str`, just like [`get()`](#method.get).
+    ///
+    /// Returns `None` if the lookup does not resolve to an existing element.
+    pub fn remove<A>(&mut self, lookup: A) -> Option<T> where A: NamedIndex<T, I> {
+        let i = match lookup.resolve(self) {
+            Some(i) if i < self.items.len() => i,
+            _ => return None,
+        };
+        Some(self.remove_at(i))
+    }
+
+    /// Removes and returns the element resolved from `lookup`, replacing it with
+    /// the last element of the vector.
+    ///
+    /// This does not preserve ordering, but is O(1). This function's argument
+    /// can be the collection's index type `I` or a `&str`, just like
+    /// [`get()`](#method.get).
+    ///
+    /// Returns `None` if the lookup does not resolve to an existing element.
+    pub fn swap_remove<A>(&mut self, lookup: A) -> Option<T> where A: NamedIndex<T, I> {
+        let i = match lookup.resolve(self) {
+            Some(i) if i < self.items.len() => i,
+            _ => return None,
+        };
+        Some(self.swap_remove_at(i))
+    }
+
+    /// Positional `remove` by a raw `usize`. `i` must be in bounds.
+    fn remove_at(&mut self, i: usize) -> T {
+        let removed = self.items.remove(i);
+        self.map.remove(removed.name());
+        // Everything that lived after `i` shifted left by one.
+        for index in self.map.values_mut() {
+            if *index > i {
+                *index -= 1;
+            }
         }
+        removed
     }
 
-    fn name_from_lookup(&self, lookup: Lookup) -> Option<String> {
-        match lookup {
-            Lookup::Name(name) => {
-                Some(name.to_owned())
-            },
-            Lookup::Index(index) => {
-                self.items.get(index).and_then(|s| Some(String::from(s.name())))
-            },
+    /// Positional `swap_remove` by a raw `usize`. `i` must be in bounds.
+    fn swap_remove_at(&mut self, i: usize) -> T {
+        let last = self.items.len() - 1;
+        let removed = self.items.swap_remove(i);
+        self.map.remove(removed.name());
+        // Unless we removed the final element, the old last element now sits at `i`.
+        if i != last {
+            let moved_name = self.items[i].name().to_owned();
+            self.map.insert(moved_name, i);
         }
+        removed
     }
 }
 
-///////////
-// Index //
-///////////
+//////////////////////
+// NamedSliceIndex //
+//////////////////////
 
-impl<'a, T: Named> Index<&'a str> for NamedVec<T> {
-    type Output = T;
+#[doc(hidden)]
+pub mod private {
+    pub trait Sealed {}
+}
 
-    fn index(&self, index: &str) -> &T {
-        self.get(index).unwrap()
-    }
+/// A helper trait used for indexing operations, modeled on std's
+/// [`SliceIndex`](https://doc.rust-lang.org/std/slice/trait.SliceIndex.html).
+///
+/// It is implemented for the collection's index type `I` and for `&str` (both
+/// yielding a single element) and for the range types (yielding a subslice).
+/// Implementing it for the index type `I` rather than for `usize` directly is
+/// what gives positional lookups compile-time separation between `NamedVec`s
+/// that use different index types. This trait is sealed by the hidden
+/// `private::Sealed` supertrait; outside of this crate it is implemented by the
+/// types that [`define_index_type!`](macro.define_index_type.html) generates.
+pub trait NamedSliceIndex<T: Named, I: Idx>: private::Sealed {
+    /// The output type returned by the indexing operations.
+    type Output: ?Sized;
+
+    /// Returns a shared reference to the output, or `None` if out of bounds.
+    fn get(self, nv: &NamedVec<T, I>) -> Option<&Self::Output>;
+
+    /// Returns a mutable reference to the output, or `None` if out of bounds.
+    fn get_mut(self, nv: &mut NamedVec<T, I>) -> Option<&mut Self::Output>;
 }
 
-impl<T: Named> Index<usize> for NamedVec<T> {
+impl private::Sealed for usize {}
+
+impl<T: Named> NamedSliceIndex<T, usize> for usize {
     type Output = T;
 
-    fn index(&self, index: usize) -> &T {
-        &self.items[index]
+    fn get(self, nv: &NamedVec<T, usize>) -> Option<&T> {
+        nv.items.get(self)
+    }
+
+    fn get_mut(self, nv: &mut NamedVec<T, usize>) -> Option<&mut T> {
+        nv.items.get_mut(self)
     }
 }
 
-impl<T: Named> Index<Range<usize>> for NamedVec<T> {
-    type Output = [T];
+impl<'a> private::Sealed for &'a str {}
+
+impl<'a, T: Named, I: Idx> NamedSliceIndex<T, I> for &'a str {
+    type Output = T;
 
-    fn index(&self, index: Range<usize>) -> &[T] {
-        &self.items[index]
+    fn get(self, nv: &NamedVec<T, I>) -> Option<&T> {
+        nv.map.get(self).and_then(move |&i| nv.items.get(i))
+    }
+
+    fn get_mut(self, nv: &mut NamedVec<T, I>) -> Option<&mut T> {
+        match nv.map.get(self).cloned() {
+            Some(i) => nv.items.get_mut(i),
+            None => None,
+        }
     }
 }
 
-impl<T: Named> Index<RangeTo<usize>> for NamedVec<T> {
-    type Output = [T];
+macro_rules! impl_range_named_slice_index {
+    ($($ty:ty),*) => {$(
+        impl private::Sealed for $ty {}
 
-    fn index(&self, index: RangeTo<usize>) -> &[T] {
-        &self.items[index]
-    }
+        impl<T: Named, I: Idx> NamedSliceIndex<T, I> for $ty {
+            type Output = [T];
+
+            fn get(self, nv: &NamedVec<T, I>) -> Option<&[T]> {
+                nv.items.get(self)
+            }
+
+            fn get_mut(self, nv: &mut NamedVec<T, I>) -> Option<&mut [T]> {
+                nv.items.get_mut(self)
+            }
+        }
+    )*};
 }
 
-impl<T: Named> Index<RangeFrom<usize>> for NamedVec<T> {
-    type Output = [T];
+impl_range_named_slice_index!(Range<usize>, RangeFrom<usize>, RangeTo<usize>, RangeFull);
 
-    fn index(&self, index: RangeFrom<usize>) -> &[T] {
-        &self.items[index]
-    }
+////////////////
+// NamedIndex //
+////////////////
+
+/// Resolves a single-element lookup to a position. Implemented for the
+/// collection's index type `I` and for `&str`, and used by the mutating
+/// positional methods ([`swap`](struct.NamedVec.html#method.swap),
+/// [`remove`](struct.NamedVec.html#method.remove),
+/// [`swap_remove`](struct.NamedVec.html#method.swap_remove) and
+/// [`insert`](struct.NamedVec.html#method.insert)).
+///
+/// Implementing it for `I` rather than for `usize` directly is what keeps these
+/// methods' index space separate between `NamedVec`s that use different index
+/// types. This trait is sealed by the hidden `private::Sealed` supertrait;
+/// outside of this crate it is implemented by the types that
+/// [`define_index_type!`](macro.define_index_type.html) generates.
+pub trait NamedIndex<T: Named, I: Idx>: private::Sealed {
+    /// Resolves to a positional index, or `None` if the lookup does not refer
+    /// to an existing element.
+    fn resolve(self, nv: &NamedVec<T, I>) -> Option<usize>;
 }
 
-impl<T: Named> Index<RangeFull> for NamedVec<T> {
-    type Output = [T];
+impl<T: Named> NamedIndex<T, usize> for usize {
+    fn resolve(self, _nv: &NamedVec<T, usize>) -> Option<usize> {
+        // A raw position is used as-is; callers bounds-check where required.
+        Some(self)
+    }
+}
 
-    fn index(&self, _index: RangeFull) -> &[T] {
-        &self.items
+impl<'a, T: Named, I: Idx> NamedIndex<T, I> for &'a str {
+    fn resolve(self, nv: &NamedVec<T, I>) -> Option<usize> {
+        nv.map.get(self).cloned()
     }
 }
 
 ///////////
-// Named //
+// Index //
 ///////////
 
-pub trait Named {
-    fn name(&self) -> &str;
-}
-
-////////////
-// Lookup //
-////////////
+impl<T: Named, I: Idx, S: NamedSliceIndex<T, I>> Index<S> for NamedVec<T, I> {
+    type Output = S::Output;
 
-/// Used to refer to elements in a `NamedVec`.
-///
-/// However, `NamedVec`'s methods
-/// are designed to avoid making the user have to create a `Lookup`.
-/// In other words, prefer `named_vec.get("foo")` to `named_vec.get(Lookup::Name("foo"))`.
-pub enum Lookup<'a> {
-    Name(&'a str),
-    Index(usize),
+    fn index(&self, index: S) -> &S::Output {
+        index.get(self).expect("no element found for index")
+    }
 }
 
-impl<'a> From<&'a str> for Lookup<'a> {
-    fn from(s: &'a str) -> Self {
-        Lookup::Name(s)
+//////////////
+// IndexMut //
+//////////////
+
+// Mutable indexing hands out a `&mut T`, which lets a caller reassign the whole
+// element (`nv[0] = x;`) or mutate its fields in place. Reassignment is fine as
+// long as the new element keeps the same name; changing the name of an element
+// in place — either by assigning one with a different name or by mutating the
+// field that `Named::name` reads — will leave the name map pointing at the wrong
+// slot and corrupt name lookups. Use `remove`/`push` (or a future `rename`) to
+// change a name instead.
+
+impl<T: Named, I: Idx, S: NamedSliceIndex<T, I>> IndexMut<S> for NamedVec<T, I> {
+    fn index_mut(&mut self, index: S) -> &mut S::Output {
+        index.get_mut(self).expect("no element found for index")
     }
 }
 
-impl<'a> From<usize> for Lookup<'a> {
-    fn from(i: usize) -> Self {
-        Lookup::Index(i)
+//////////////////
+// IntoIterator //
+//////////////////
+
+impl<T: Named, I: Idx> IntoIterator for NamedVec<T, I> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
     }
 }
 
-/////////////////
-// MultiLookup //
-/////////////////
-
-// This won't be useful until std::slice::SliceIndex is stable
-enum MultiLookup<'a> {
-    Name(&'a str),
-    Index(usize),
-    Range(Range<usize>),
-    RangeFrom(RangeFrom<usize>),
-    RangeTo(RangeTo<usize>),
-    RangeFull(RangeFull),
-}
+impl<'a, T: Named, I: Idx> IntoIterator for &'a NamedVec<T, I> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
 
-impl<'a> From<&'a str> for MultiLookup<'a> {
-    fn from(s: &'a str) -> Self {
-        MultiLookup::Name(s)
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
     }
 }
 
-impl<'a> From<usize> for MultiLookup<'a> {
-    fn from(i: usize) -> Self {
-        MultiLookup::Index(i)
+impl<'a, T: Named, I: Idx> IntoIterator for &'a mut NamedVec<T, I> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter_mut()
     }
 }
 
-impl<'a> From<Range<usize>> for MultiLookup<'a> {
-    fn from(i: Range<usize>) -> Self {
-        MultiLookup::Range(i)
+//////////////////////////
+// FromIterator / Extend //
+//////////////////////////
+
+impl<T: Named, Ix: Idx> std::iter::FromIterator<T> for NamedVec<T, Ix> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut nv = NamedVec::new();
+        nv.extend(iter);
+        nv
     }
 }
 
-impl<'a> From<RangeFrom<usize>> for MultiLookup<'a> {
-    fn from(i: RangeFrom<usize>) -> Self {
-        MultiLookup::RangeFrom(i)
+impl<T: Named, Ix: Idx> Extend<T> for NamedVec<T, Ix> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // Route through `push` so name-uniqueness/replace semantics hold:
+        // a later item with a duplicate name overwrites the earlier one.
+        for item in iter {
+            self.push(item);
+        }
     }
 }
 
-impl<'a> From<RangeTo<usize>> for MultiLookup<'a> {
-    fn from(i: RangeTo<usize>) -> Self {
-        MultiLookup::RangeTo(i)
-    }
+///////////
+// Named //
+///////////
+
+pub trait Named {
+    fn name(&self) -> &str;
+}
+
+/////////
+// Idx //
+/////////
+
+/// An integer index type for a [`NamedVec`](struct.NamedVec.html).
+///
+/// `usize` implements `Idx` and is the default. Supplying a distinct newtype
+/// instead gives compile-time separation between the index spaces of different
+/// collections. Use [`define_index_type!`](macro.define_index_type.html) to
+/// generate such a newtype rather than implementing this trait by hand.
+pub trait Idx: Copy {
+    /// Creates an index from a `usize`.
+    fn from_usize(i: usize) -> Self;
+
+    /// Returns this index as a `usize`.
+    fn index(self) -> usize;
 }
 
-impl<'a> From<RangeFull> for MultiLookup<'a> {
-    fn from(i: RangeFull) -> Self {
-        MultiLookup::RangeFull(i)
+impl Idx for usize {
+    fn from_usize(i: usize) -> Self {
+        i
     }
+
+    fn index(self) -> usize {
+        self
+    }
+}
+
+/// Generates a newtype index that can be used as the second type parameter of
+/// [`NamedVec`](struct.NamedVec.html).
+///
+/// The generated type wraps a single integer, implements [`Idx`](trait.Idx.html),
+/// and can be used for positional access (`nv.get(MyId(0))`, `nv[MyId(0)]`,
+/// `nv.remove(MyId(0))`, …) while `&str` name lookups keep working. Because each
+/// collection carries its own index type, an index produced by one `NamedVec`
+/// cannot be used with another.
+///
+/// ```ignore
+/// define_index_type! { pub struct WidgetId(u32); }
+/// let mut widgets: NamedVec<Widget, WidgetId> = NamedVec::new();
+/// ```
+#[macro_export]
+macro_rules! define_index_type {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($inner:ty);) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis struct $name(pub $inner);
+
+        impl $crate::Idx for $name {
+            fn from_usize(i: usize) -> Self {
+                $name(i as $inner)
+            }
+
+            fn index(self) -> usize {
+                self.0 as usize
+            }
+        }
+
+        impl $crate::private::Sealed for $name {}
+
+        impl<T: $crate::Named> $crate::NamedSliceIndex<T, $name> for $name {
+            type Output = T;
+
+            fn get(self, nv: &$crate::NamedVec<T, $name>) -> ::std::option::Option<&T> {
+                nv.get_at($crate::Idx::index(self))
+            }
+
+            fn get_mut(self, nv: &mut $crate::NamedVec<T, $name>) -> ::std::option::Option<&mut T> {
+                nv.get_at_mut($crate::Idx::index(self))
+            }
+        }
+
+        impl<T: $crate::Named> $crate::NamedIndex<T, $name> for $name {
+            fn resolve(self, _nv: &$crate::NamedVec<T, $name>) -> ::std::option::Option<usize> {
+                ::std::option::Option::Some($crate::Idx::index(self))
+            }
+        }
+    };
 }
+